@@ -0,0 +1,150 @@
+//! # Use APA102 / SK9822 leds via spi
+//!
+//! Unlike the WS2812, these are clock+data (real SPI) chipsets, so there is no
+//! timing constraint on the SPI frequency and this driver coexists cleanly
+//! with the WS2812 codepath.
+//!
+//! Each LED carries an independent 5-bit brightness field that is PWM'd by the
+//! chip itself, giving much smoother low-end dimming than scaling the 8-bit
+//! color alone.
+
+use embedded_hal as hal;
+
+use hal::spi::SpiBus;
+
+use smart_leds_trait::{SmartLedsWrite, RGB8};
+
+use crate::{Correction, GammaTable};
+
+/// The maximum value of the 5-bit per-pixel brightness field.
+pub const MAX_BRIGHTNESS: u8 = 0b0001_1111;
+
+/// An APA102 pixel: an RGB color plus an optional per-pixel 5-bit brightness.
+///
+/// `brightness` is `Some(value)` for an explicit per-pixel brightness (clamped
+/// to [`MAX_BRIGHTNESS`] when encoded) or `None` to fall back to the strip-wide
+/// default set via [`Apa102::new_with_hw_brightness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pixel {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub brightness: Option<u8>,
+}
+
+impl From<RGB8> for Pixel {
+    /// Use an RGB color at the strip-wide default brightness.
+    fn from(color: RGB8) -> Self {
+        Self {
+            r: color.r,
+            g: color.g,
+            b: color.b,
+            brightness: None,
+        }
+    }
+}
+
+/// Use APA102 / SK9822 devices via spi, modeled on FastLED's APA102 controller.
+pub struct Apa102<SPI> {
+    spi: SPI,
+    brightness: u8,
+    correction: Correction,
+}
+
+impl<SPI, E> Apa102<SPI>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    /// Use apa102 devices via spi at full strip-wide brightness.
+    ///
+    /// These chips are clock-driven, so any SPI frequency the bus supports
+    /// works.
+    pub fn new(spi: SPI) -> Self {
+        Self {
+            spi,
+            brightness: MAX_BRIGHTNESS,
+            correction: Correction::new(),
+        }
+    }
+
+    /// Set a strip-wide brightness scalar applied to the 8-bit color channels
+    /// before sending. This is independent of the hardware 5-bit brightness
+    /// field set via [`new_with_hw_brightness`](Self::new_with_hw_brightness).
+    pub fn set_color_brightness(&mut self, brightness: u8) -> &mut Self {
+        self.correction.brightness = brightness;
+        self
+    }
+
+    /// Set a gamma lookup table applied to the color channels before sending.
+    pub fn set_gamma(&mut self, gamma: GammaTable) -> &mut Self {
+        self.correction.gamma = Some(gamma);
+        self
+    }
+
+    /// Use apa102 devices via spi with a strip-wide default for the hardware
+    /// 5-bit brightness field (0 to [`MAX_BRIGHTNESS`]).
+    ///
+    /// The default is used for pixels whose `brightness` is `None` (including
+    /// those supplied as plain [`RGB8`]); a `Pixel` with an explicit
+    /// `Some(value)` keeps that value.
+    pub fn new_with_hw_brightness(spi: SPI, brightness: u8) -> Self {
+        Self {
+            spi,
+            brightness: brightness.min(MAX_BRIGHTNESS),
+            correction: Correction::new(),
+        }
+    }
+
+    /// Write a single LED frame: the `0b111_bbbbb` header byte followed by the
+    /// color in BGR order, with brightness/gamma correction applied.
+    fn write_pixel(&mut self, pixel: Pixel) -> Result<(), E> {
+        let brightness = pixel.brightness.unwrap_or(self.brightness).min(MAX_BRIGHTNESS);
+        let header = 0b1110_0000 | brightness;
+        let b = self.correction.apply(pixel.b);
+        let g = self.correction.apply(pixel.g);
+        let r = self.correction.apply(pixel.r);
+        self.spi.write(&[header, b, g, r])
+    }
+
+    /// Write the 4-byte start frame of zeroes.
+    fn start_frame(&mut self) -> Result<(), E> {
+        self.spi.write(&[0x00, 0x00, 0x00, 0x00])
+    }
+
+    /// Write the end frame: at least `ceil(n/2)` bits (i.e. `ceil(n/16)` bytes)
+    /// of `0xFF` to clock the last pixels all the way through the strip.
+    fn end_frame(&mut self, count: usize) -> Result<(), E> {
+        let bytes = count.div_ceil(16);
+        for _ in 0..bytes {
+            self.spi.write(&[0xFF])?;
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, E> SmartLedsWrite for Apa102<SPI>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    type Error = E;
+    type Color = Pixel;
+    /// Write all the items of an iterator to an apa102 strip
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), E>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<Self::Color>,
+    {
+        self.start_frame()?;
+
+        let mut count = 0;
+        for item in iterator {
+            let pixel: Pixel = item.into();
+            // `write_pixel` resolves a `None` brightness to the strip-wide
+            // default, leaving explicit per-pixel values untouched.
+            self.write_pixel(pixel)?;
+            count += 1;
+        }
+
+        self.end_frame(count)
+    }
+}
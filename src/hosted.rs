@@ -87,10 +87,7 @@ where
     }
 }
 
-impl<SPI, D, E> Ws2812<SPI, D, PO>
-where
-    SPI: SpiBus<u8, Error = E>,
-{
+impl<SPI, D, PO> Ws2812<SPI, D, PO> {
     /// Write a single byte for ws2812 devices
     fn write_byte(&mut self, mut data: u8) {
         // Send two bits in one spi byte. High time first, then the low time
@@ -103,7 +100,12 @@ where
             data <<= 2;
         }
     }
+}
 
+impl<SPI, D, E, PO> Ws2812<SPI, D, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
     fn send_data(&mut self) -> Result<(), E> {
         self.data.extend_from_slice(&[0; 140]);
         self.spi.write(&self.data)?;
@@ -159,3 +161,66 @@ where
         self.send_data()
     }
 }
+
+#[cfg(feature = "async")]
+impl<SPI, D, E, PO> Ws2812<SPI, D, PO>
+where
+    SPI: embedded_hal_async::spi::SpiBus<u8, Error = E>,
+{
+    async fn send_data_async(&mut self) -> Result<(), E> {
+        self.data.extend_from_slice(&[0; 140]);
+        self.spi.write(&self.data).await?;
+        self.data.truncate(140);
+        Ok(())
+    }
+}
+
+/// Asynchronous, non-blocking write path built on [`embedded_hal_async::spi::SpiBus`].
+///
+/// The output buffer is filled synchronously; only the single `write` of the
+/// assembled buffer is an `.await` point.
+#[cfg(feature = "async")]
+impl<SPI, E, PO> Ws2812<SPI, devices::Ws2812, PO>
+where
+    SPI: embedded_hal_async::spi::SpiBus<u8, Error = E>,
+    PO: OrderedColors,
+{
+    /// Write all the items of an iterator to a ws2812 strip, asynchronously
+    pub async fn write<T, I>(&mut self, iterator: T) -> Result<(), E>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        for item in iterator {
+            let color: RGB8 = item.into();
+            let ordered_color = PO::order(color);
+            self.write_byte(ordered_color[0]);
+            self.write_byte(ordered_color[1]);
+            self.write_byte(ordered_color[2]);
+        }
+        self.send_data_async().await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI, E> Ws2812<SPI, devices::Sk6812w>
+where
+    SPI: embedded_hal_async::spi::SpiBus<u8, Error = E>,
+{
+    /// Write all the items of an iterator to a ws2812 strip, asynchronously
+    pub async fn write<T, I>(&mut self, iterator: T) -> Result<(), E>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGBW<u8, u8>>,
+    {
+        for item in iterator {
+            let item = item.into();
+            // SK6812W always expects GRBW order
+            self.write_byte(item.g);
+            self.write_byte(item.r);
+            self.write_byte(item.b);
+            self.write_byte(item.a.0);
+        }
+        self.send_data_async().await
+    }
+}
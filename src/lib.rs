@@ -14,6 +14,7 @@
 
 use embedded_hal as hal;
 
+pub mod apa102;
 #[cfg(feature = "std")]
 pub mod hosted;
 pub mod prerendered;
@@ -21,7 +22,6 @@ pub mod prerendered;
 use hal::spi::{Mode, Phase, Polarity, SpiBus};
 
 use core::marker::PhantomData;
-use core::slice::from_ref;
 
 use smart_leds_trait::{SmartLedsWrite, RGB8, RGBW};
 
@@ -74,16 +74,242 @@ impl_ordered_colors!(GBR, g, b, r);
 impl_ordered_colors!(BRG, b, r, g);
 impl_ordered_colors!(BGR, b, g, r);
 
-pub struct Ws2812<SPI, DEVICE = devices::Ws2812, PIXELORDER = pixel_order::GRB> {
+/// Pixel order for RGBW (SK6812W-like) devices. The white channel is always
+/// emitted last, so e.g. `GRB` becomes `GRBW`.
+pub trait OrderedColorsW {
+    fn order_w(color: RGBW<u8, u8>) -> [u8; 4];
+}
+
+macro_rules! impl_ordered_colors_w {
+    ($struct_name:ident, $r_field:ident, $g_field:ident, $b_field:ident) => {
+        impl OrderedColorsW for pixel_order::$struct_name {
+            fn order_w(color: RGBW<u8, u8>) -> [u8; 4] {
+                [color.$r_field, color.$g_field, color.$b_field, color.a.0]
+            }
+        }
+    };
+}
+
+impl_ordered_colors_w!(RGB, r, g, b);
+impl_ordered_colors_w!(RBG, r, b, g);
+impl_ordered_colors_w!(GRB, g, r, b);
+impl_ordered_colors_w!(GBR, g, b, r);
+impl_ordered_colors_w!(BRG, b, r, g);
+impl_ordered_colors_w!(BGR, b, g, r);
+
+/// Accumulates SPI bits MSB-first and yields whole bytes as they fill up.
+///
+/// Encodings append a few SPI bits per WS2812 data bit; because a data bit
+/// does not have to map to a whole number of SPI bytes, leftover bits are
+/// carried here across `write_byte` calls and flushed once a full byte is
+/// available (or at the end of a frame).
+pub struct BitAccumulator {
+    acc: u32,
+    len: usize,
+}
+
+impl BitAccumulator {
+    const fn new() -> Self {
+        Self { acc: 0, len: 0 }
+    }
+
+    /// Append the low `count` bits of `bits`, MSB-first within those bits.
+    fn push(&mut self, bits: u32, count: usize) {
+        self.acc = (self.acc << count) | (bits & ((1 << count) - 1));
+        self.len += count;
+    }
+
+    /// Remove and return a full byte if at least eight bits are buffered.
+    fn pop_byte(&mut self) -> Option<u8> {
+        if self.len >= 8 {
+            self.len -= 8;
+            Some(((self.acc >> self.len) & 0xff) as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Flush the remaining partial byte, zero-padded on the right (the pad
+    /// bits are low, so they merge into the following reset sequence).
+    fn flush(&mut self) -> Option<u8> {
+        if self.len > 0 {
+            let byte = ((self.acc << (8 - self.len)) & 0xff) as u8;
+            self.acc = 0;
+            self.len = 0;
+            Some(byte)
+        } else {
+            None
+        }
+    }
+}
+
+/// How a single WS2812 data bit is expanded into SPI bits.
+///
+/// Different encodings trade RAM and bus time against the range of usable SPI
+/// clocks. See the [`encoding`] module for the shipped implementations.
+pub trait BitEncoding {
+    /// Number of SPI bits emitted per WS2812 data bit.
+    const SPI_BITS_PER_BIT: usize;
+    /// Number of trailing zero bytes forming the reset/latch sequence at this
+    /// encoding's SPI clock.
+    const RESET_BYTES: usize;
+    /// Append the SPI pattern for one data `bit` to `acc`, MSB first.
+    fn encode_bit(acc: &mut BitAccumulator, bit: bool);
+}
+
+/// A 256-entry gamma lookup table.
+#[derive(Debug, Clone, Copy)]
+pub struct GammaTable(pub [u8; 256]);
+
+impl GammaTable {
+    /// Use a caller-supplied table.
+    pub const fn new(table: [u8; 256]) -> Self {
+        Self(table)
+    }
+
+    /// The default table, generated for γ ≈ 2.2.
+    pub const fn gamma_2_2() -> Self {
+        Self(GAMMA_2_2)
+    }
+
+    fn apply(&self, channel: u8) -> u8 {
+        self.0[channel as usize]
+    }
+}
+
+/// Optional brightness scaling and gamma correction applied to every channel
+/// before encoding.
+///
+/// Brightness scaling follows FastLED's global scaler and the Linux WS2812B
+/// driver's intensity concept; gamma is a plain table lookup.
+#[derive(Debug, Clone, Copy)]
+struct Correction {
+    brightness: u8,
+    gamma: Option<GammaTable>,
+}
+
+impl Correction {
+    const fn new() -> Self {
+        Self {
+            brightness: 255,
+            gamma: None,
+        }
+    }
+
+    /// Gamma-correct, then brightness-scale, a single channel.
+    fn apply(&self, channel: u8) -> u8 {
+        let channel = match &self.gamma {
+            Some(gamma) => gamma.apply(channel),
+            None => channel,
+        };
+        ((channel as u16 * (self.brightness as u16 + 1)) >> 8) as u8
+    }
+}
+
+#[rustfmt::skip]
+static GAMMA_2_2: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2,
+    3, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6,
+    6, 7, 7, 7, 8, 8, 8, 9, 9, 9, 10, 10, 11, 11, 11, 12,
+    12, 13, 13, 13, 14, 14, 15, 15, 16, 16, 17, 17, 18, 18, 19, 19,
+    20, 20, 21, 22, 22, 23, 23, 24, 25, 25, 26, 26, 27, 28, 28, 29,
+    30, 30, 31, 32, 33, 33, 34, 35, 35, 36, 37, 38, 39, 39, 40, 41,
+    42, 43, 43, 44, 45, 46, 47, 48, 49, 49, 50, 51, 52, 53, 54, 55,
+    56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71,
+    73, 74, 75, 76, 77, 78, 79, 81, 82, 83, 84, 85, 87, 88, 89, 90,
+    91, 93, 94, 95, 97, 98, 99, 100, 102, 103, 105, 106, 107, 109, 110, 111,
+    113, 114, 116, 117, 119, 120, 121, 123, 124, 126, 127, 129, 130, 132, 133, 135,
+    137, 138, 140, 141, 143, 145, 146, 148, 149, 151, 153, 154, 156, 158, 159, 161,
+    163, 165, 166, 168, 170, 172, 173, 175, 177, 179, 181, 182, 184, 186, 188, 190,
+    192, 194, 196, 197, 199, 201, 203, 205, 207, 209, 211, 213, 215, 217, 219, 221,
+    223, 225, 227, 229, 231, 234, 236, 238, 240, 242, 244, 246, 248, 251, 253, 255,
+];
+
+/// Error returned by the buffered, single-transaction write path.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// The supplied scratch buffer was too small for the frame.
+    OutOfBounds,
+    Spi(E),
+}
+
+/// Encode one data byte into `buf` through the accumulator, returning `false`
+/// if the buffer is exhausted.
+fn encode_byte_into<ENC: BitEncoding>(
+    buf: &mut [u8],
+    idx: &mut usize,
+    acc: &mut BitAccumulator,
+    mut data: u8,
+) -> bool {
+    for _ in 0..8 {
+        ENC::encode_bit(acc, data & 0b1000_0000 != 0);
+        while let Some(byte) = acc.pop_byte() {
+            if *idx >= buf.len() {
+                return false;
+            }
+            buf[*idx] = byte;
+            *idx += 1;
+        }
+        data <<= 1;
+    }
+    true
+}
+
+/// The bit encodings shipped with this crate.
+pub mod encoding {
+    use super::{BitAccumulator, BitEncoding};
+
+    /// Four SPI bits per data bit (`one = 0b1110`, `zero = 0b1000`).
+    ///
+    /// The default: 12 bytes per RGB pixel and the most timing headroom,
+    /// usable at roughly 2 MHz to 3.8 MHz.
+    pub struct FourBit;
+
+    impl BitEncoding for FourBit {
+        const SPI_BITS_PER_BIT: usize = 4;
+        const RESET_BYTES: usize = 140;
+        fn encode_bit(acc: &mut BitAccumulator, bit: bool) {
+            acc.push(if bit { 0b1110 } else { 0b1000 }, 4);
+        }
+    }
+
+    /// Three SPI bits per data bit (`one = 0b110`, `zero = 0b100`).
+    ///
+    /// Shrinks the buffer to 9 bytes per RGB pixel and targets an SPI clock of
+    /// ~2.4 MHz, which still yields the 800 kHz LED rate.
+    pub struct ThreeBit;
+
+    impl BitEncoding for ThreeBit {
+        const SPI_BITS_PER_BIT: usize = 3;
+        // 140 bytes at the 4-bit clock scaled by 3/4 still clears the ~300 µs
+        // latch at the slower 2.4 MHz clock.
+        const RESET_BYTES: usize = 105;
+        fn encode_bit(acc: &mut BitAccumulator, bit: bool) {
+            acc.push(if bit { 0b110 } else { 0b100 }, 3);
+        }
+    }
+}
+
+pub struct Ws2812<
+    SPI,
+    DEVICE = devices::Ws2812,
+    PIXELORDER = pixel_order::GRB,
+    ENCODING = encoding::FourBit,
+> {
     spi: SPI,
+    correction: Correction,
+    reset_bytes: Option<usize>,
     _device: PhantomData<DEVICE>,
     _pixel_order: PhantomData<PIXELORDER>,
+    _encoding: PhantomData<ENCODING>,
 }
 
-impl<SPI, E, PO> Ws2812<SPI, devices::Ws2812, PO>
+impl<SPI, E, PO, ENC> Ws2812<SPI, devices::Ws2812, PO, ENC>
 where
     SPI: SpiBus<u8, Error = E>,
     PO: OrderedColors,
+    ENC: BitEncoding,
 {
     /// Use ws2812 devices via spi
     ///
@@ -96,15 +322,19 @@ where
     pub fn new(spi: SPI) -> Self {
         Self {
             spi,
+            correction: Correction::new(),
+            reset_bytes: None,
             _device: PhantomData {},
             _pixel_order: PhantomData {},
+            _encoding: PhantomData {},
         }
     }
 }
 
-impl<SPI, E, PO> Ws2812<SPI, devices::Sk6812w, PO>
+impl<SPI, E, PO, ENC> Ws2812<SPI, devices::Sk6812w, PO, ENC>
 where
     SPI: SpiBus<u8, Error = E>,
+    ENC: BitEncoding,
 {
     /// Use sk6812w devices via spi
     ///
@@ -119,40 +349,460 @@ where
     pub fn new_sk6812w(spi: SPI) -> Self {
         Self {
             spi,
+            correction: Correction::new(),
+            reset_bytes: None,
             _device: PhantomData {},
             _pixel_order: PhantomData {},
+            _encoding: PhantomData {},
         }
     }
 }
 
-impl<SPI, D, E, PO> Ws2812<SPI, D, PO>
+impl<SPI, D, E, PO, ENC> Ws2812<SPI, D, PO, ENC>
 where
     SPI: SpiBus<u8, Error = E>,
+    ENC: BitEncoding,
 {
+    /// Set a strip-wide brightness scalar applied to every channel before
+    /// encoding. `255` (the default) leaves the color untouched.
+    pub fn set_brightness(&mut self, brightness: u8) -> &mut Self {
+        self.correction.brightness = brightness;
+        self
+    }
+
+    /// Set a gamma lookup table applied to every channel before encoding.
+    pub fn set_gamma(&mut self, gamma: GammaTable) -> &mut Self {
+        self.correction.gamma = Some(gamma);
+        self
+    }
+
+    /// Set the latch/reset time in microseconds for the actual SPI clock
+    /// frequency `spi_hz`, overriding the encoding's default.
+    ///
+    /// The number of trailing zero bytes is computed as
+    /// `ceil(spi_hz * latch_us / 1e6 / 8)`. Classic WS2812 parts latch in
+    /// ~50 µs, while the SK6812 and WS2812B-V5 need up to ~280 µs.
+    pub fn with_latch_us(&mut self, latch_us: u16, spi_hz: u32) -> &mut Self {
+        let bits = spi_hz as u64 * latch_us as u64;
+        self.reset_bytes = Some(bits.div_ceil(8_000_000) as usize);
+        self
+    }
+
     /// Write a single byte for ws2812 devices
+    ///
+    /// Each data bit is expanded by the `ENC` encoding into a few SPI bits,
+    /// which are streamed out byte-by-byte through the shared accumulator. The
+    /// high time comes first, then the low time.
+    fn write_byte(&mut self, acc: &mut BitAccumulator, mut data: u8) -> Result<(), E> {
+        for _ in 0..8 {
+            ENC::encode_bit(acc, data & 0b1000_0000 != 0);
+            while let Some(byte) = acc.pop_byte() {
+                self.spi.write(&[byte])?;
+            }
+            data <<= 1;
+        }
+        Ok(())
+    }
+
+    /// Flush any bits left in the accumulator, padded into a final byte.
+    fn flush(&mut self, acc: &mut BitAccumulator) -> Result<(), E> {
+        if let Some(byte) = acc.flush() {
+            self.spi.write(&[byte])?;
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), E> {
+        // The number of zero bytes follows from the encoding's SPI clock, or
+        // from an explicit latch time set via `with_latch_us`.
+        let reset_bytes = self.reset_bytes.unwrap_or(ENC::RESET_BYTES);
+        for _ in 0..reset_bytes {
+            self.spi.write(&[0])?;
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, E, PO: OrderedColors, ENC: BitEncoding> SmartLedsWrite
+    for Ws2812<SPI, devices::Ws2812, PO, ENC>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    type Error = E;
+    type Color = RGB8;
+    /// Write all the items of an iterator to a ws2812 strip
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), E>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<Self::Color>,
+    {
+        if cfg!(feature = "mosi_idle_high") {
+            self.reset()?;
+        }
+
+        let mut acc = BitAccumulator::new();
+        for item in iterator {
+            let color: RGB8 = item.into();
+            let ordered_color = PO::order(color);
+            self.write_byte(&mut acc, self.correction.apply(ordered_color[0]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered_color[1]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered_color[2]))?;
+        }
+        self.flush(&mut acc)?;
+        self.reset()?;
+        Ok(())
+    }
+}
+
+impl<SPI, E, PO: OrderedColorsW, ENC: BitEncoding> SmartLedsWrite
+    for Ws2812<SPI, devices::Sk6812w, PO, ENC>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    type Error = E;
+    type Color = RGBW<u8, u8>;
+    /// Write all the items of an iterator to a ws2812 strip
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), E>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<Self::Color>,
+    {
+        if cfg!(feature = "mosi_idle_high") {
+            self.reset()?;
+        }
+
+        let mut acc = BitAccumulator::new();
+        for item in iterator {
+            let ordered = PO::order_w(item.into());
+            self.write_byte(&mut acc, self.correction.apply(ordered[0]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered[1]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered[2]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered[3]))?;
+        }
+        self.flush(&mut acc)?;
+        self.reset()?;
+        Ok(())
+    }
+}
+
+impl<SPI, E, PO, ENC> Ws2812<SPI, devices::Ws2812, PO, ENC>
+where
+    SPI: SpiBus<u8, Error = E>,
+    PO: OrderedColors,
+    ENC: BitEncoding,
+{
+    /// Encode the whole iterator plus the trailing reset into `buf` and send
+    /// it in exactly one `SpiBus::write`.
+    ///
+    /// Unlike [`SmartLedsWrite::write`], which streams many small transfers,
+    /// this assembles one contiguous frame, which both removes the per-call
+    /// overhead and avoids the inter-byte gaps that corrupt WS2812 timing on
+    /// some HALs. `buf` must hold `3 * ENC::SPI_BITS_PER_BIT * N` bytes for the
+    /// pixels (e.g. 12·N for `FourBit`) plus the reset bytes (`ENC::RESET_BYTES`,
+    /// or the count set via [`with_latch_us`](Self::with_latch_us)), otherwise
+    /// [`Error::OutOfBounds`] is returned.
+    pub fn write_buffered<T, I>(&mut self, iterator: T, buf: &mut [u8]) -> Result<(), Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        let mut acc = BitAccumulator::new();
+        let mut idx = 0;
+        for item in iterator {
+            let color: RGB8 = item.into();
+            let ordered_color = PO::order(color);
+            for byte in ordered_color {
+                let byte = self.correction.apply(byte);
+                if !encode_byte_into::<ENC>(buf, &mut idx, &mut acc, byte) {
+                    return Err(Error::OutOfBounds);
+                }
+            }
+        }
+        if let Some(byte) = acc.flush() {
+            if idx >= buf.len() {
+                return Err(Error::OutOfBounds);
+            }
+            buf[idx] = byte;
+            idx += 1;
+        }
+        let reset_bytes = self.reset_bytes.unwrap_or(ENC::RESET_BYTES);
+        if idx + reset_bytes > buf.len() {
+            return Err(Error::OutOfBounds);
+        }
+        for slot in &mut buf[idx..idx + reset_bytes] {
+            *slot = 0;
+        }
+        idx += reset_bytes;
+
+        self.spi.write(&buf[..idx]).map_err(Error::Spi)
+    }
+}
+
+/// Runtime-selectable pixel order, the dynamic counterpart to the
+/// [`pixel_order`] type markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorOrder {
+    RGB,
+    RBG,
+    GRB,
+    GBR,
+    BRG,
+    BGR,
+}
+
+impl ColorOrder {
+    /// Reorder the channels of `color` according to this order.
+    pub fn order(self, color: RGB8) -> [u8; 3] {
+        match self {
+            ColorOrder::RGB => [color.r, color.g, color.b],
+            ColorOrder::RBG => [color.r, color.b, color.g],
+            ColorOrder::GRB => [color.g, color.r, color.b],
+            ColorOrder::GBR => [color.g, color.b, color.r],
+            ColorOrder::BRG => [color.b, color.r, color.g],
+            ColorOrder::BGR => [color.b, color.g, color.r],
+        }
+    }
+}
+
+/// Runtime-selectable device kind, the dynamic counterpart to the [`devices`]
+/// type markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKind {
+    Ws2812,
+    Sk6812w,
+}
+
+/// A WS2812-like driver whose pixel order and device kind are chosen at
+/// runtime instead of through type parameters.
+///
+/// This lets a single firmware image drive a mix of e.g. GRB WS2812 strips and
+/// RGBW SK6812 strips, or pick the order from a config read at boot, at the
+/// cost of a small per-pixel branch. For zero-cost static dispatch use the
+/// typed [`Ws2812`] instead.
+///
+/// Pixels are always supplied as [`RGBW`]; the white channel is ignored for
+/// [`DeviceKind::Ws2812`].
+pub struct Ws2812Dynamic<SPI, ENCODING = encoding::FourBit> {
+    spi: SPI,
+    order: ColorOrder,
+    device: DeviceKind,
+    correction: Correction,
+    _encoding: PhantomData<ENCODING>,
+}
+
+impl<SPI, E, ENC> Ws2812Dynamic<SPI, ENC>
+where
+    SPI: SpiBus<u8, Error = E>,
+    ENC: BitEncoding,
+{
+    /// Use a WS2812-like device via spi, with the color order and device kind
+    /// selected at runtime.
+    ///
+    /// The SPI bus should run within the range required by the chosen encoding.
+    pub fn new(spi: SPI, order: ColorOrder, device: DeviceKind) -> Self {
+        Self {
+            spi,
+            order,
+            device,
+            correction: Correction::new(),
+            _encoding: PhantomData {},
+        }
+    }
+
+    /// Reconfigure the color order without rebuilding the driver.
+    pub fn set_order(&mut self, order: ColorOrder) {
+        self.order = order;
+    }
+
+    /// Reconfigure the device kind without rebuilding the driver.
+    pub fn set_device(&mut self, device: DeviceKind) {
+        self.device = device;
+    }
+
+    /// Set a strip-wide brightness scalar applied to every channel before
+    /// encoding. `255` (the default) leaves the color untouched.
+    pub fn set_brightness(&mut self, brightness: u8) -> &mut Self {
+        self.correction.brightness = brightness;
+        self
+    }
+
+    /// Set a gamma lookup table applied to every channel before encoding.
+    pub fn set_gamma(&mut self, gamma: GammaTable) -> &mut Self {
+        self.correction.gamma = Some(gamma);
+        self
+    }
+
+    fn write_byte(&mut self, acc: &mut BitAccumulator, mut data: u8) -> Result<(), E> {
+        for _ in 0..8 {
+            ENC::encode_bit(acc, data & 0b1000_0000 != 0);
+            while let Some(byte) = acc.pop_byte() {
+                self.spi.write(&[byte])?;
+            }
+            data <<= 1;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, acc: &mut BitAccumulator) -> Result<(), E> {
+        if let Some(byte) = acc.flush() {
+            self.spi.write(&[byte])?;
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), E> {
+        for _ in 0..ENC::RESET_BYTES {
+            self.spi.write(&[0])?;
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, E, ENC: BitEncoding> SmartLedsWrite for Ws2812Dynamic<SPI, ENC>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    type Error = E;
+    type Color = RGBW<u8, u8>;
+    /// Write all the items of an iterator to a WS2812-like strip
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), E>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<Self::Color>,
+    {
+        if cfg!(feature = "mosi_idle_high") {
+            self.reset()?;
+        }
+
+        let mut acc = BitAccumulator::new();
+        for item in iterator {
+            let item = item.into();
+            let ordered = self.order.order(RGB8 {
+                r: item.r,
+                g: item.g,
+                b: item.b,
+            });
+            self.write_byte(&mut acc, self.correction.apply(ordered[0]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered[1]))?;
+            self.write_byte(&mut acc, self.correction.apply(ordered[2]))?;
+            if self.device == DeviceKind::Sk6812w {
+                self.write_byte(&mut acc, self.correction.apply(item.a.0))?;
+            }
+        }
+        self.flush(&mut acc)?;
+        self.reset()?;
+        Ok(())
+    }
+}
+
+/// A WS2812-like driver that serializes each data bit into a full 8-bit SPI
+/// frame, using caller-supplied `one_frame` / `zero_frame` byte patterns.
+///
+/// Each data bit becomes one SPI byte whose leading run of set bits sets the
+/// high time (24 SPI bytes per RGB pixel, 32 with white), the way Zephyr's
+/// `ws2812_spi` driver works. This trades RAM for timing robustness and lets
+/// the SPI peripheral run at ~6.4 MHz, where an 8-bit frame spans one ~1.25 µs
+/// bit period, so the exact high/low durations can be calibrated per chip.
+///
+/// Unlike the 3- and 4-bit schemes in [`encoding`], a caller-supplied frame is
+/// not a fixed bit pattern and so cannot be expressed as a [`BitEncoding`];
+/// this type is therefore kept separate rather than folded into [`Ws2812`].
+pub struct Ws2812Framed<SPI, DEVICE = devices::Ws2812, PIXELORDER = pixel_order::GRB> {
+    spi: SPI,
+    one_frame: u8,
+    zero_frame: u8,
+    correction: Correction,
+    _device: PhantomData<DEVICE>,
+    _pixel_order: PhantomData<PIXELORDER>,
+}
+
+impl<SPI, E, PO> Ws2812Framed<SPI, devices::Ws2812, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+    PO: OrderedColors,
+{
+    /// Use ws2812 devices via spi with a one-byte-per-bit encoding.
+    ///
+    /// `one_frame` / `zero_frame` are the SPI bytes emitted for a set and a
+    /// cleared data bit respectively (e.g. `0b1111_1000` and `0b1100_0000` at
+    /// ~6.4 MHz). The SPI clock should make one 8-bit frame span a ~1.25 µs
+    /// LED bit period.
+    pub fn new_with_encoding(spi: SPI, one_frame: u8, zero_frame: u8) -> Self {
+        Self {
+            spi,
+            one_frame,
+            zero_frame,
+            correction: Correction::new(),
+            _device: PhantomData {},
+            _pixel_order: PhantomData {},
+        }
+    }
+}
+
+impl<SPI, E, PO> Ws2812Framed<SPI, devices::Sk6812w, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    /// Use sk6812w devices via spi with a one-byte-per-bit encoding.
+    ///
+    /// See [`Ws2812Framed::<devices::Ws2812>::new_with_encoding`] for the frame
+    /// bytes.
+    pub fn new_sk6812w_with_encoding(spi: SPI, one_frame: u8, zero_frame: u8) -> Self {
+        Self {
+            spi,
+            one_frame,
+            zero_frame,
+            correction: Correction::new(),
+            _device: PhantomData {},
+            _pixel_order: PhantomData {},
+        }
+    }
+}
+
+impl<SPI, D, E, PO> Ws2812Framed<SPI, D, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    /// One 8-bit frame per bit puts the SPI clock at ~6.4 MHz, so clearing the
+    /// ~300 µs latch needs about 240 zero bytes.
+    const RESET_BYTES: usize = 240;
+
+    /// Set a strip-wide brightness scalar applied to every channel before
+    /// encoding. `255` (the default) leaves the color untouched.
+    pub fn set_brightness(&mut self, brightness: u8) -> &mut Self {
+        self.correction.brightness = brightness;
+        self
+    }
+
+    /// Set a gamma lookup table applied to every channel before encoding.
+    pub fn set_gamma(&mut self, gamma: GammaTable) -> &mut Self {
+        self.correction.gamma = Some(gamma);
+        self
+    }
+
+    /// Write a single byte, one SPI frame per data bit, high time first.
     fn write_byte(&mut self, mut data: u8) -> Result<(), E> {
-        // Send two bits in one spi byte. High time first, then the low time
-        // The maximum for T0H is 500ns, the minimum for one bit 1063 ns.
-        // These result in the upper and lower spi frequency limits
-        let patterns = [0b1000_1000, 0b1000_1110, 0b11101000, 0b11101110];
-        for _ in 0..4 {
-            let bits = (data & 0b1100_0000) >> 6;
-            self.spi.write(from_ref(&patterns[bits as usize]))?;
-            data <<= 2;
+        for _ in 0..8 {
+            let frame = if data & 0b1000_0000 != 0 {
+                self.one_frame
+            } else {
+                self.zero_frame
+            };
+            self.spi.write(&[frame])?;
+            data <<= 1;
         }
         Ok(())
     }
 
     fn reset(&mut self) -> Result<(), E> {
-        // Should be > 300Î¼s, so for an SPI Freq. of 3.8MHz, we have to send at least 1140 low bits or 140 low bytes
-        for _ in 0..140 {
-            self.spi.write(from_ref(&0))?;
+        for _ in 0..Self::RESET_BYTES {
+            self.spi.write(&[0])?;
         }
         Ok(())
     }
 }
 
-impl<SPI, E, PO: OrderedColors> SmartLedsWrite for Ws2812<SPI, devices::Ws2812, PO>
+impl<SPI, E, PO: OrderedColors> SmartLedsWrite for Ws2812Framed<SPI, devices::Ws2812, PO>
 where
     SPI: SpiBus<u8, Error = E>,
 {
@@ -171,16 +821,16 @@ where
         for item in iterator {
             let color: RGB8 = item.into();
             let ordered_color = PO::order(color);
-            self.write_byte(ordered_color[0])?;
-            self.write_byte(ordered_color[1])?;
-            self.write_byte(ordered_color[2])?;
+            self.write_byte(self.correction.apply(ordered_color[0]))?;
+            self.write_byte(self.correction.apply(ordered_color[1]))?;
+            self.write_byte(self.correction.apply(ordered_color[2]))?;
         }
         self.reset()?;
         Ok(())
     }
 }
 
-impl<SPI, E, PO> SmartLedsWrite for Ws2812<SPI, devices::Sk6812w, PO>
+impl<SPI, E, PO> SmartLedsWrite for Ws2812Framed<SPI, devices::Sk6812w, PO>
 where
     SPI: SpiBus<u8, Error = E>,
 {
@@ -199,12 +849,16 @@ where
         for item in iterator {
             let item = item.into();
             // SK6812W always expects GRBW order
-            self.write_byte(item.g)?;
-            self.write_byte(item.r)?;
-            self.write_byte(item.b)?;
-            self.write_byte(item.a.0)?;
+            self.write_byte(self.correction.apply(item.g))?;
+            self.write_byte(self.correction.apply(item.r))?;
+            self.write_byte(self.correction.apply(item.b))?;
+            self.write_byte(self.correction.apply(item.a.0))?;
         }
         self.reset()?;
         Ok(())
     }
 }
+
+// The compact 3-bit-per-bit encoding (`1 -> 0b110`, `0 -> 0b100`) is exposed
+// through the generic `Ws2812<_, _, _, encoding::ThreeBit>` rather than a
+// separate driver; see [`encoding::ThreeBit`].
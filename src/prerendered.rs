@@ -36,6 +36,53 @@ pub mod devices {
     pub struct Sk6812w;
 }
 
+/// A handle to a transfer that has been started in the background (e.g. on a
+/// DMA channel) by [`DmaSpiBus::write_dma`].
+///
+/// The token carries the length of the frame being transmitted, so it is tied
+/// to the specific transfer it represents rather than being an opaque marker.
+pub struct TransferToken {
+    len: usize,
+}
+
+impl TransferToken {
+    /// Construct a token for a transfer of `len` bytes, from a HAL
+    /// implementation of [`DmaSpiBus::write_dma`] (typically
+    /// `TransferToken::new(data.len())`).
+    pub fn new(len: usize) -> Self {
+        Self { len }
+    }
+
+    /// The length, in bytes, of the frame being transmitted.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the transfer carries no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// An SPI bus that can transmit a `'static` buffer in the background, so the
+/// CPU is free while the bytes are clocked out.
+///
+/// This is the extension point for DMA-capable HALs (e.g. RP2040 SPI-TX via
+/// DMA): [`Ws2812::render`] fills the buffer and returns the exact slice to
+/// send, [`Ws2812::start`] hands that slice to [`write_dma`](Self::write_dma)
+/// and returns its [`TransferToken`], and [`is_done`](DmaSpiBus::is_done)
+/// reports when the transfer has finished, allowing the next frame to be
+/// rendered into a second buffer meanwhile.
+pub trait DmaSpiBus {
+    /// Start transmitting `data` in the background and return immediately. The
+    /// returned token should be built with the length of `data`
+    /// (`TransferToken::new(data.len())`).
+    fn write_dma(&mut self, data: &'static [u8]) -> TransferToken;
+
+    /// Returns `true` once the most recently started transfer has completed.
+    fn is_done(&self) -> bool;
+}
+
 pub struct Ws2812<'a, SPI, DEVICE = devices::Ws2812, PIXELORDER = pixel_order::GRB> {
     spi: SPI,
     data: &'a mut [u8],
@@ -105,12 +152,11 @@ where
     }
 }
 
-impl<SPI, D, E, PO> Ws2812<'_, SPI, D, PO>
-where
-    SPI: SpiBus<u8, Error = E>,
-{
+impl<SPI, D, PO> Ws2812<'_, SPI, D, PO> {
     /// Write a single byte for WS2812-like devices
-    fn write_byte(&mut self, mut data: u8) -> Result<(), Error<E>> {
+    // The error type is a method parameter so the rendering helpers can be
+    // shared between the blocking and the async send paths.
+    fn write_byte<E>(&mut self, mut data: u8) -> Result<(), Error<E>> {
         // Send two bits in one spi byte. High time first, then the low time
         // The maximum for T0H is 500ns, the minimum for one bit 1063 ns.
         // These result in the upper and lower spi frequency limits
@@ -130,7 +176,7 @@ where
 
     /// Add a reset sequence (140 zeroes) to the data buffer
     // Is always used for `mosi_idle_high`, as otherwise the time required to fill the buffer can lead to idle cycles on the SPI bus
-    fn write_reset(&mut self) -> Result<(), Error<E>> {
+    fn write_reset<E>(&mut self) -> Result<(), Error<E>> {
         if self.index + RESET_DATA_LEN > self.data.len() {
             return Err(Error::OutOfBounds);
         }
@@ -140,7 +186,140 @@ where
         }
         Ok(())
     }
+}
+
+impl<'a, SPI, PO> Ws2812<'a, SPI, devices::Ws2812, PO>
+where
+    PO: OrderedColors,
+{
+    /// Reset the buffer and render the pixel data (plus the leading reset
+    /// padding used by `mosi_idle_high`), *without* the trailing reset. Shared
+    /// by [`render`](Self::render) and the blocking/async write paths so the
+    /// encoding loop is not duplicated.
+    fn buffer_pixels<E, T, I>(&mut self, iterator: T) -> Result<(), Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        self.index = 0;
+
+        if cfg!(feature = "mosi_idle_high") {
+            self.write_reset()?;
+        }
+
+        for item in iterator {
+            let color: RGB8 = item.into();
+            let ordered_color = PO::order(color);
+            self.write_byte(ordered_color[0])?;
+            self.write_byte(ordered_color[1])?;
+            self.write_byte(ordered_color[2])?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, SPI, E, PO> Ws2812<'a, SPI, devices::Ws2812, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+    PO: OrderedColors,
+{
+    /// Render a whole frame (pixel data plus the trailing reset padding) into
+    /// the internal buffer *without* transmitting, returning the exact slice
+    /// that must be sent.
+    ///
+    /// This is the rendering half of the split-phase API: hand the returned
+    /// slice to a background transfer (see [`start`](Self::start)), or busy-wait
+    /// on a [`DmaSpiBus`] token, while rendering the next frame elsewhere.
+    ///
+    /// The trailing reset is always rendered, so the buffer needs the extra
+    /// 140 bytes listed under [`new`](Self::new) for `reset_single_transaction`
+    /// whether or not that feature is enabled.
+    pub fn render<T, I>(&mut self, iterator: T) -> Result<&[u8], Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        self.buffer_pixels(iterator)?;
+
+        // The reset padding is part of the single contiguous buffer so the
+        // whole frame can go out in one background transfer.
+        self.write_reset()?;
+
+        Ok(&self.data[..self.index])
+    }
+}
+
+impl<'a, SPI, PO> Ws2812<'a, SPI, devices::Sk6812w, PO> {
+    /// Reset the buffer and render the SK6812W pixel data (plus the leading
+    /// reset padding used by `mosi_idle_high`), *without* the trailing reset.
+    fn buffer_pixels<E, T, I>(&mut self, iterator: T) -> Result<(), Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGBW<u8, u8>>,
+    {
+        self.index = 0;
+
+        if cfg!(feature = "mosi_idle_high") {
+            self.write_reset()?;
+        }
+
+        for item in iterator {
+            let item = item.into();
+            // SK6812W always expects GRBW order
+            self.write_byte(item.g)?;
+            self.write_byte(item.r)?;
+            self.write_byte(item.b)?;
+            self.write_byte(item.a.0)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, SPI, E, PO> Ws2812<'a, SPI, devices::Sk6812w, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
+    /// Render a whole SK6812W frame into the internal buffer without
+    /// transmitting. See [`Ws2812::<devices::Ws2812>::render`].
+    pub fn render<T, I>(&mut self, iterator: T) -> Result<&[u8], Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGBW<u8, u8>>,
+    {
+        self.buffer_pixels(iterator)?;
+
+        self.write_reset()?;
+
+        Ok(&self.data[..self.index])
+    }
+}
+
+impl<SPI, D, PO> Ws2812<'static, SPI, D, PO>
+where
+    SPI: DmaSpiBus,
+{
+    /// Start a non-blocking transfer of the frame most recently produced by
+    /// [`render`](Self::render) and return a [`TransferToken`]. Poll the token
+    /// via [`DmaSpiBus::is_done`]; the buffer must not be touched until it
+    /// reports completion.
+    ///
+    /// Only available when the buffer handed to `new` is `'static`, as the DMA
+    /// engine reads it in the background after this call has returned.
+    pub fn start(&mut self) -> TransferToken {
+        // SAFETY: the rendered bytes live in the `'static` buffer passed to
+        // `new`, so they remain valid for the whole background transfer. The
+        // caller contract (documented above) forbids re-rendering until the
+        // transfer has completed, so there is no aliasing write.
+        let frame: &'static [u8] =
+            unsafe { core::slice::from_raw_parts(self.data.as_ptr(), self.index) };
+        self.spi.write_dma(frame)
+    }
+}
 
+impl<SPI, D, E, PO> Ws2812<'_, SPI, D, PO>
+where
+    SPI: SpiBus<u8, Error = E>,
+{
     /// Send a reset sequence (140 zeroes) on the bus
     fn send_reset(&mut self) -> Result<(), Error<E>> {
         for _ in 0..RESET_DATA_LEN {
@@ -168,19 +347,7 @@ where
         T: IntoIterator<Item = I>,
         I: Into<Self::Color>,
     {
-        self.index = 0;
-
-        if cfg!(feature = "mosi_idle_high") {
-            self.write_reset()?;
-        }
-
-        for item in iterator {
-            let color: RGB8 = item.into();
-            let ordered_color = PO::order(color);
-            self.write_byte(ordered_color[0])?;
-            self.write_byte(ordered_color[1])?;
-            self.write_byte(ordered_color[2])?;
-        }
+        self.buffer_pixels(iterator)?;
 
         if cfg!(feature = "reset_single_transaction") {
             self.write_reset()?;
@@ -207,29 +374,94 @@ where
         T: IntoIterator<Item = I>,
         I: Into<Self::Color>,
     {
-        self.index = 0;
+        self.buffer_pixels(iterator)?;
 
-        if cfg!(feature = "mosi_idle_high") {
+        if cfg!(feature = "reset_single_transaction") {
             self.write_reset()?;
         }
 
-        for item in iterator {
-            let item = item.into();
-            // SK6812W always expects GRBW order
-            self.write_byte(item.g)?;
-            self.write_byte(item.r)?;
-            self.write_byte(item.b)?;
-            self.write_byte(item.a.0)?;
+        self.send_data().map_err(Error::Spi)?;
+
+        if !cfg!(feature = "reset_single_transaction") {
+            self.send_reset()?;
         }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI, D, E, PO> Ws2812<'_, SPI, D, PO>
+where
+    SPI: embedded_hal_async::spi::SpiBus<u8, Error = E>,
+{
+    /// Send a reset sequence (140 zeroes) on the bus
+    async fn send_reset_async(&mut self) -> Result<(), Error<E>> {
+        for _ in 0..RESET_DATA_LEN {
+            self.spi.write(&[0]).await.map_err(Error::Spi)?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_data_async(&mut self) -> Result<(), E> {
+        self.spi.write(&self.data[..self.index]).await
+    }
+}
+
+/// Asynchronous, non-blocking write path built on [`embedded_hal_async::spi::SpiBus`].
+///
+/// The buffer is rendered synchronously via `write_byte`; only the actual
+/// transmission of the prerendered data and the reset sequence are `.await`
+/// points, so the executor is free to run other tasks (e.g. rendering the next
+/// frame) while the current one is streamed out.
+#[cfg(feature = "async")]
+impl<SPI, E, PO> Ws2812<'_, SPI, devices::Ws2812, PO>
+where
+    SPI: embedded_hal_async::spi::SpiBus<u8, Error = E>,
+    PO: OrderedColors,
+{
+    /// Write all the items of an iterator to a WS2812 strip, asynchronously
+    pub async fn write<T, I>(&mut self, iterator: T) -> Result<(), Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        self.buffer_pixels(iterator)?;
 
         if cfg!(feature = "reset_single_transaction") {
             self.write_reset()?;
         }
 
-        self.send_data().map_err(Error::Spi)?;
+        self.send_data_async().await.map_err(Error::Spi)?;
 
         if !cfg!(feature = "reset_single_transaction") {
-            self.send_reset()?;
+            self.send_reset_async().await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI, E, PO> Ws2812<'_, SPI, devices::Sk6812w, PO>
+where
+    SPI: embedded_hal_async::spi::SpiBus<u8, Error = E>,
+{
+    /// Write all the items of an iterator to a SK6812W strip, asynchronously
+    pub async fn write<T, I>(&mut self, iterator: T) -> Result<(), Error<E>>
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<RGBW<u8, u8>>,
+    {
+        self.buffer_pixels(iterator)?;
+
+        if cfg!(feature = "reset_single_transaction") {
+            self.write_reset()?;
+        }
+
+        self.send_data_async().await.map_err(Error::Spi)?;
+
+        if !cfg!(feature = "reset_single_transaction") {
+            self.send_reset_async().await?;
         }
         Ok(())
     }